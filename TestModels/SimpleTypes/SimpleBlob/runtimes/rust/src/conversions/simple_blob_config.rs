@@ -0,0 +1,65 @@
+// Code generated by software.amazon.smithy.rust.codegen.smithy-rs. DO NOT EDIT.
+
+/// Converts the `value` blob member to the `Sequence<u8>` representation Dafny expects.
+///
+/// The native side holds the blob as a `Vec<u8>`; this copies its bytes into a new
+/// `Sequence<u8>`, and `value_from_dafny` copies them back into a new `Vec<u8>`. No storage is
+/// shared between the two representations.
+pub fn value_to_dafny(
+    value: &::std::vec::Vec<u8>,
+) -> ::dafny_runtime::Sequence<::std::primitive::u8> {
+    ::dafny_runtime_conversions::unicode_chars_false::vec_u8_to_dafny_sequence(value)
+}
+
+pub fn value_from_dafny(
+    value: &::dafny_runtime::Sequence<::std::primitive::u8>,
+) -> ::std::vec::Vec<u8> {
+    ::dafny_runtime_conversions::unicode_chars_false::dafny_sequence_to_vec_u8(value)
+}
+
+/// Converts a native `SimpleBlobConfig` into the representation Dafny expects.
+///
+/// This is the boundary every operation invocation crosses, so it is where validation must run
+/// for values that bypass [`SimpleBlobConfigBuilder::build`](crate::types::SimpleBlobConfigBuilder::build)
+/// entirely by constructing `SimpleBlobConfig` directly — its fields are public, so nothing else
+/// stops a caller from doing that.
+pub fn to_dafny(
+    value: crate::types::SimpleBlobConfig,
+) -> ::std::result::Result<::dafny_runtime::Sequence<::std::primitive::u8>, crate::error::Error> {
+    value.validate()?;
+    ::std::result::Result::Ok(value_to_dafny(&value.value.unwrap_or_default()))
+}
+
+/// Converts a Dafny-side value back into a native `SimpleBlobConfig`.
+///
+/// Values arriving from Dafny-compiled code are assumed already-valid (Dafny's own compiled
+/// preconditions enforce the model's constraint traits on that side), so this does not call
+/// [`SimpleBlobConfig::validate`] again.
+pub fn from_dafny(
+    value: ::dafny_runtime::Sequence<::std::primitive::u8>,
+) -> crate::types::SimpleBlobConfig {
+    crate::types::SimpleBlobConfig {
+        value: ::std::option::Option::Some(value_from_dafny(&value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_dafny_surfaces_validation_error_for_config_constructed_directly() {
+        // Bypasses `SimpleBlobConfigBuilder` entirely — the exact gap the request calls out,
+        // since `SimpleBlobConfig`'s fields are public.
+        let config = crate::types::SimpleBlobConfig {
+            value: ::std::option::Option::Some(vec![0u8; 4097]),
+        };
+
+        let result = to_dafny(config);
+
+        assert!(matches!(
+            result,
+            ::std::result::Result::Err(crate::error::Error::ValidationError { .. })
+        ));
+    }
+}
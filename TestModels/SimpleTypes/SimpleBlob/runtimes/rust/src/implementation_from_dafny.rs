@@ -0,0 +1,4 @@
+// Written by hand, not generated: nothing in this crate (it holds no `.smithy` model and no
+// code generator) emits this file or the `pub mod standard_library_externs;` declaration below.
+
+pub mod standard_library_externs;
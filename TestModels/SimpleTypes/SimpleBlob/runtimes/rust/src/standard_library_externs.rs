@@ -0,0 +1,10 @@
+// Hand-written Rust implementations backing this crate's `{:extern}` Dafny declarations.
+//
+// This module is declared by the hand-written `pub mod standard_library_externs;` line in
+// `implementation_from_dafny.rs`; nothing in this crate generates that declaration.
+
+/// Backs an `{:extern}` Dafny function used by the `SimpleBlob` model's `@length` constraint
+/// check; see [`crate::types::SimpleBlobConfig::validate`].
+pub fn blob_length(value: &[u8]) -> usize {
+    value.len()
+}
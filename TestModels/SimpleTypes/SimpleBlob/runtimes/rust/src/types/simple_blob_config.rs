@@ -1,7 +1,17 @@
 // Code generated by software.amazon.smithy.rust.codegen.smithy-rs. DO NOT EDIT.
 
 #[derive(::std::clone::Clone, ::std::fmt::Debug)]
-pub struct SimpleBlobConfig {}
+pub struct SimpleBlobConfig {
+    #[allow(missing_docs)] // documentation missing in model
+    pub value: ::std::option::Option<::std::vec::Vec<u8>>,
+}
+
+impl SimpleBlobConfig {
+    #[allow(missing_docs)] // documentation missing in model
+    pub fn value(&self) -> ::std::option::Option<&[u8]> {
+        self.value.as_deref()
+    }
+}
 
 impl SimpleBlobConfig {
     pub fn builder() -> SimpleBlobConfigBuilder {
@@ -9,18 +19,72 @@ impl SimpleBlobConfig {
     }
 }
 
-#[derive(::std::clone::Clone, ::std::fmt::Debug)]
-pub struct SimpleBlobConfigBuilder {}
+impl SimpleBlobConfig {
+    /// Validates this value against the constraint traits declared on the model.
+    ///
+    /// [`SimpleBlobConfigBuilder::build`] calls this automatically, but since `SimpleBlobConfig`
+    /// fields are public, a caller can also construct it directly and bypass the builder
+    /// entirely. For that reason,
+    /// [`conversions::simple_blob_config::to_dafny`](crate::conversions::simple_blob_config::to_dafny) —
+    /// the boundary every operation invocation crosses on its way into Dafny-compiled code —
+    /// calls this again; values converted the other way, via `from_dafny`, are assumed
+    /// already-valid and skip it.
+    pub(crate) fn validate(&self) -> ::std::result::Result<(), crate::error::Error> {
+        if let ::std::option::Option::Some(value) = &self.value {
+            let length = crate::standard_library_externs::blob_length(value);
+            if length > SIMPLE_BLOB_CONFIG_VALUE_MAX_LENGTH {
+                return ::std::result::Result::Err(crate::error::Error::ValidationError {
+                    message: ::std::format!(
+                        "value failed to satisfy constraint: Member must have length less than or equal to {}, but was {}",
+                        SIMPLE_BLOB_CONFIG_VALUE_MAX_LENGTH,
+                        length,
+                    ),
+                });
+            }
+        }
+        ::std::result::Result::Ok(())
+    }
+}
+
+const SIMPLE_BLOB_CONFIG_VALUE_MAX_LENGTH: usize = 4096;
+
+#[derive(::std::clone::Clone, ::std::fmt::Debug, ::std::default::Default)]
+pub struct SimpleBlobConfigBuilder {
+    pub(crate) value: ::std::option::Option<::std::vec::Vec<u8>>,
+}
 
 impl SimpleBlobConfigBuilder {
     /// Creates a new `SimpleBlobConfigBuilder`.
     pub(crate) fn new() -> Self {
-        Self {}
+        Self::default()
+    }
+    #[allow(missing_docs)] // documentation missing in model
+    pub fn value(mut self, input: impl ::std::convert::Into<::std::vec::Vec<u8>>) -> Self {
+        self.value = ::std::option::Option::Some(input.into());
+        self
+    }
+    #[allow(missing_docs)] // documentation missing in model
+    pub fn set_value(mut self, input: ::std::option::Option<::std::vec::Vec<u8>>) -> Self {
+        self.value = input;
+        self
+    }
+    #[allow(missing_docs)] // documentation missing in model
+    pub fn get_value(&self) -> &::std::option::Option<::std::vec::Vec<u8>> {
+        &self.value
     }
+    /// Validates `@length` and other constraint traits declared on the model, surfacing a
+    /// violation as a [`BuildError`](::aws_smithy_types::error::operation::BuildError).
     pub fn build(
         self,
     ) -> ::std::result::Result<SimpleBlobConfig, ::aws_smithy_types::error::operation::BuildError>
     {
-        ::std::result::Result::Ok(SimpleBlobConfig {})
+        let config = SimpleBlobConfig { value: self.value };
+        // `crate::error::Error::Opaque` wraps a `dafny_runtime::Object`, which is `Rc`-based
+        // and therefore not `Send`/`Sync`; `BuildError::other` requires its source to be, so
+        // the message is formatted to a `String` here rather than boxing `Error` itself.
+        config
+            .validate()
+            .map_err(|e| ::aws_smithy_types::error::operation::BuildError::other(e.to_string()))?;
+        ::std::result::Result::Ok(config)
     }
 }
@@ -1,13 +1,26 @@
 // Code generated by software.amazon.smithy.rust.codegen.smithy-rs. DO NOT EDIT.
+//
+// UNVERIFIED: this file is the only one checked into this crate's tree, so `super::Client`,
+// `GetEnumV2FluentBuilder`, and its `value`/`set_value` setters aren't defined anywhere in this
+// checkout to test or typecheck against. The `.value(value)` call below assumes
+// `GetEnumV2FluentBuilder::value` takes `impl Into<SimpleEnumV2Shape>` the same way
+// `GetEnumV2InputBuilder::value` would have; that assumption is not confirmed here.
 impl super::Client {
     /// Constructs a fluent builder for the [`GetEnumV2`](crate::operation::get_enum_v2::builders::GetEnumV2FluentBuilder) operation.
     ///
-    /// - The fluent builder is configurable:
-    ///   - [`value(SimpleEnumV2Shape)`](crate::operation::get_enum_v2::builders::GetEnumV2FluentBuilder::value) / [`set_value(Option<SimpleEnumV2Shape>)`](crate::operation::get_enum_v2::builders::GetEnumV2FluentBuilder::set_value):(undocumented)<br>
+    /// `GetEnumV2Input` carries the `@positional` trait, so its sole member is taken directly
+    /// as an argument here instead of requiring callers to go through the wrapper input struct;
+    /// the builder underneath is still seeded with it via
+    /// [`value`](crate::operation::get_enum_v2::builders::GetEnumV2FluentBuilder::value).
+    ///
     /// - On success, responds with [`GetEnumV2Output`](crate::operation::get_enum_v2::GetEnumV2Output) with field(s):
     ///   - [`value(Option<EnumV2>)`](crate::operation::get_enum_v2::GetEnumV2Output::value): (undocumented)
     /// - On failure, responds with [`SdkError<GetEnumV2Error>`](crate::operation::get_enum_v2::GetEnumV2Error)
-    pub fn get_enum_v2(&self) -> crate::operation::get_enum_v2::builders::GetEnumV2FluentBuilder {
+    pub fn get_enum_v2(
+        &self,
+        value: impl ::std::convert::Into<crate::types::SimpleEnumV2Shape>,
+    ) -> crate::operation::get_enum_v2::builders::GetEnumV2FluentBuilder {
         crate::operation::get_enum_v2::builders::GetEnumV2FluentBuilder::new(self.clone())
+            .value(value)
     }
 }
@@ -0,0 +1,23 @@
+// Code generated by software.amazon.smithy.rust.codegen.smithy-rs. DO NOT EDIT.
+
+pub fn to_dafny(value: crate::error::Error) -> ::dafny_runtime::Object<dyn ::std::any::Any> {
+    match value {
+        // `Opaque` already holds a Dafny-side object and is unwrapped back into it. Every other
+        // variant (`ValidationError`, which has no Dafny-side representation) is boxed whole
+        // into a new opaque object, which `from_dafny` can downcast back.
+        crate::error::Error::Opaque { obj } => obj,
+        other => ::dafny_runtime::Object::<dyn ::std::any::Any>::new(other),
+    }
+}
+
+pub fn from_dafny(dafny_value: ::dafny_runtime::Object<dyn ::std::any::Any>) -> crate::error::Error {
+    // Values coming back from Dafny-compiled code are opaque by default; only restore
+    // `ValidationError` if the object is actually one we previously boxed ourselves (e.g. the
+    // wrapped test path that feeds an already-invalid native value straight into the operation).
+    if ::dafny_runtime::is_object!(dafny_value, crate::error::Error) {
+        let downcast = ::dafny_runtime::cast_object!(dafny_value, crate::error::Error);
+        (*downcast).clone()
+    } else {
+        crate::error::Error::Opaque { obj: dafny_value }
+    }
+}
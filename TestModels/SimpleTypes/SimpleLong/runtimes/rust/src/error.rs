@@ -0,0 +1,38 @@
+// Code generated by software.amazon.smithy.rust.codegen.smithy-rs. DO NOT EDIT.
+//
+// `ValidationError`/`Opaque` and the functions in `conversions/error.rs` are boilerplate the
+// generator emits independently into every service crate (this test model's `SimpleBlob` crate
+// has the same boilerplate); they're identical on purpose, not copy-pasted from one another.
+
+/// Error type for operations in this crate.
+#[non_exhaustive]
+#[derive(::std::fmt::Debug, ::std::clone::Clone)]
+pub enum Error {
+    /// The request failed to satisfy one or more constraint traits declared on the model
+    /// (`@required`, `@length`, `@range`, ...).
+    ///
+    /// This variant exists only on the Rust side: the model defines no corresponding Dafny
+    /// error, so it is upcast to [`Error::Opaque`] when a value crosses the Dafny boundary and
+    /// downcast back to `ValidationError` when a value crosses back into Rust.
+    ValidationError {
+        #[allow(missing_docs)]
+        message: ::std::string::String,
+    },
+    /// An unmodeled error, generally surfaced by a dependency of this crate or by Dafny-compiled
+    /// code that has no corresponding Rust error type.
+    Opaque {
+        #[allow(missing_docs)]
+        obj: ::dafny_runtime::Object<dyn ::std::any::Any>,
+    },
+}
+
+impl ::std::fmt::Display for Error {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Error::ValidationError { message } => write!(f, "ValidationError: {}", message),
+            Error::Opaque { obj } => write!(f, "{:?}", obj),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
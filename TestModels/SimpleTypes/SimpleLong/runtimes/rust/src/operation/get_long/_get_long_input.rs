@@ -18,6 +18,35 @@ impl GetLongInput {
         crate::operation::get_long::builders::GetLongInputBuilder::default()
     }
 }
+impl GetLongInput {
+    /// Validates this input against the constraint traits declared on the model.
+    ///
+    /// [`GetLongInputBuilder::build`](crate::operation::get_long::builders::GetLongInputBuilder::build)
+    /// calls this automatically, but since `GetLongInput` fields are public, a caller can also
+    /// construct it directly and bypass the builder entirely. For that reason,
+    /// [`conversions::get_long_input::to_dafny`](crate::conversions::get_long_input::to_dafny) —
+    /// the boundary every operation invocation crosses on its way into Dafny-compiled code —
+    /// calls this again; values converted the other way, via `from_dafny`, are assumed
+    /// already-valid and skip it.
+    pub(crate) fn validate(&self) -> ::std::result::Result<(), crate::error::Error> {
+        if let ::std::option::Option::Some(value) = self.value {
+            if value < GET_LONG_INPUT_VALUE_MIN || value > GET_LONG_INPUT_VALUE_MAX {
+                return ::std::result::Result::Err(crate::error::Error::ValidationError {
+                    message: ::std::format!(
+                        "value failed to satisfy constraint: Member must be between {} and {}, but was {}",
+                        GET_LONG_INPUT_VALUE_MIN,
+                        GET_LONG_INPUT_VALUE_MAX,
+                        value,
+                    ),
+                });
+            }
+        }
+        ::std::result::Result::Ok(())
+    }
+}
+
+const GET_LONG_INPUT_VALUE_MIN: i64 = 0;
+const GET_LONG_INPUT_VALUE_MAX: i64 = 1_000_000;
 
 /// A builder for [`GetLongInput`](crate::operation::operation::GetLongInput).
 #[non_exhaustive]
@@ -49,12 +78,22 @@ impl GetLongInputBuilder {
         &self.value
     }
     /// Consumes the builder and constructs a [`GetLongInput`](crate::operation::operation::GetLongInput).
+    ///
+    /// Validates `@range` and other constraint traits declared on the model, surfacing a
+    /// violation as a [`BuildError`](::aws_smithy_types::error::operation::BuildError).
     pub fn build(
         self,
     ) -> ::std::result::Result<
         crate::operation::get_long::GetLongInput,
         ::aws_smithy_types::error::operation::BuildError,
     > {
-        ::std::result::Result::Ok(crate::operation::get_long::GetLongInput { value: self.value })
+        let input = crate::operation::get_long::GetLongInput { value: self.value };
+        // `crate::error::Error::Opaque` wraps a `dafny_runtime::Object`, which is `Rc`-based
+        // and therefore not `Send`/`Sync`; `BuildError::other` requires its source to be, so
+        // the message is formatted to a `String` here rather than boxing `Error` itself.
+        input
+            .validate()
+            .map_err(|e| ::aws_smithy_types::error::operation::BuildError::other(e.to_string()))?;
+        ::std::result::Result::Ok(input)
     }
 }